@@ -24,7 +24,8 @@ fn main() {
     siv.run();
 }
 
-fn show_next_window(siv: &mut Cursive, lore: &str) {
+// TODO: pass the selected lore to get_next_prompt
+fn show_next_window(siv: &mut Cursive, _lore: &str) {
     siv.pop_layer();
 
     // println!("{}", lore);
@@ -52,7 +53,7 @@ fn show_next_window(siv: &mut Cursive, lore: &str) {
         panel_choices(),
         ResizedView::new(
             SizeConstraint::Full,
-            SizeConstraint::Fixed(screen_size.y * 1 / 4),
+            SizeConstraint::Fixed(screen_size.y / 4),
             second_layout_inner,
         ),
     );
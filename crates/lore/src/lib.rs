@@ -4,16 +4,23 @@ pub fn get_all_availables_lore() -> Vec<Lore> {
     let lores: Vec<Lore> = vec![
         Lore {
             title: "warhammer 40k".to_string(),
+            description:
+                "Un futur lointain et sombre où l’Imperium de l’Humanité lutte pour sa survie."
+                    .to_string(),
         },
         Lore {
             title: "loup garou".to_string(),
+            description: "Un village isolé où des loups-garous se cachent parmi les habitants."
+                .to_string(),
         },
         Lore {
             title: "esa magia".to_string(),
+            description: "Un monde où la magie façonne les royaumes et ceux qui la maîtrisent."
+                .to_string(),
         },
     ];
 
-    return lores;
+    lores
 }
 
 // #[cfg(test)]
@@ -1,9 +1,9 @@
 use lore::get_all_availables_lore;
 use prompt::build;
-use structs::{Lore, Prompt};
+use structs::{Lore, LoreSummary, Prompt};
 
 pub fn main() -> Vec<Lore> {
-    return get_all_availables_lore();
+    get_all_availables_lore()
 }
 
 pub fn get_lore_titles() -> Vec<String> {
@@ -13,6 +13,32 @@ pub fn get_lore_titles() -> Vec<String> {
         .collect()
 }
 
-pub fn get_next_prompt(choosen_action: Option<String>) -> Prompt {
+pub fn get_lore_summaries() -> Vec<LoreSummary> {
+    get_all_availables_lore()
+        .into_iter()
+        .map(|lore| LoreSummary {
+            title: lore.title,
+            description: lore.description,
+            item_count: None,
+        })
+        .collect()
+}
+
+pub fn get_next_prompt(_choosen_action: Option<String>) -> Prompt {
     build()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lore_summaries_match_titles() {
+        let summaries = get_lore_summaries();
+        let titles: Vec<String> = summaries.iter().map(|s| s.title.clone()).collect();
+
+        assert_eq!(titles, get_lore_titles());
+        assert!(summaries.iter().all(|s| !s.description.is_empty()));
+        assert!(summaries.iter().all(|s| s.item_count.is_none()));
+    }
+}
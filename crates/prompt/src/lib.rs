@@ -2,5 +2,5 @@ use llm::generate;
 use structs::Prompt;
 
 pub fn build () -> Prompt {
-    return generate();
+    generate()
 }
\ No newline at end of file
@@ -1,5 +1,13 @@
 pub struct Lore {
     pub title: String,
+    pub description: String,
+}
+
+pub struct LoreSummary {
+    pub title: String,
+    pub description: String,
+    // None until lores are backed by an indexable set of items
+    pub item_count: Option<usize>,
 }
 
 pub struct Prompt {